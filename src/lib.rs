@@ -9,6 +9,19 @@ pub mod wb_valuation{
         net_income + depreciation_amortization - maintenance_capex
     }
 
+    /// Calculates after-tax Owner's Earnings, applying the effective tax rate to the pre-tax figure.
+    /// Formula: owners_earnings(...) * (1 - effective_tax_rate)
+    // Calcula os Lucros do Proprietário após impostos, aplicando a alíquota efetiva ao valor pré-imposto.
+    // Fórmula: owners_earnings(...) * (1 - alíquota_efetiva)
+    pub fn after_tax_owners_earnings(
+        net_income: f64,
+        depreciation_amortization: f64,
+        maintenance_capex: f64,
+        effective_tax_rate: f64,
+    ) -> f64 {
+        owners_earnings(net_income, depreciation_amortization, maintenance_capex) * (1.0 - effective_tax_rate)
+    }
+
     /// Calculates Return on Equity (ROE) as a percentage.
     /// Formula: (Net Income / Shareholders' Equity) * 100
     // Calcula o Retorno sobre o Patrimônio (ROE) como percentual.
@@ -117,12 +130,378 @@ pub mod wb_valuation{
             Some(intrinsic_value(initial_owners_earnings, growth_rate, discount_rate, years) / shares_outstanding)
         }
     }
+
+    /// Calculates intrinsic value using a two-stage DCF: an explicit projection plus a
+    /// Gordon-growth terminal value for everything beyond `years`.
+    /// Formula: intrinsic_value(...) + (E_years * (1 + perpetuity_growth_rate) / (discount_rate - perpetuity_growth_rate)) / (1 + discount_rate)^years
+    /// Returns `None` when `discount_rate <= perpetuity_growth_rate`, since the terminal value formula diverges.
+    // Calcula o valor intrínseco usando um DCF de dois estágios: uma projeção explícita mais um
+    // valor terminal de crescimento de Gordon para tudo além de `years`.
+    // Fórmula: intrinsic_value(...) + (E_anos * (1 + taxa_crescimento_perpetuidade) / (taxa_desconto - taxa_crescimento_perpetuidade)) / (1 + taxa_desconto)^anos
+    // Retorna `None` quando `discount_rate <= perpetuity_growth_rate`, pois a fórmula do valor terminal diverge.
+    pub fn intrinsic_value_with_terminal(
+        initial_owners_earnings: f64,
+        growth_rate: f64,
+        discount_rate: f64,
+        years: u32,
+        perpetuity_growth_rate: f64,
+    ) -> Option<f64> {
+        if discount_rate <= perpetuity_growth_rate {
+            None // Avoid a diverging/negative terminal value
+            // Evita um valor terminal divergente/negativo
+        } else {
+            let explicit_value = intrinsic_value(initial_owners_earnings, growth_rate, discount_rate, years);
+            // Owner's earnings at the end of the explicit projection
+            // Lucros do proprietário ao final da projeção explícita
+            let final_owners_earnings = initial_owners_earnings * (1.0 + growth_rate).powf(years as f64);
+            let terminal_value = final_owners_earnings * (1.0 + perpetuity_growth_rate) / (discount_rate - perpetuity_growth_rate);
+            let discounted_terminal_value = terminal_value / (1.0 + discount_rate).powf(years as f64);
+            Some(explicit_value + discounted_terminal_value)
+        }
+    }
+
+    /// Calculates the cost of equity using the Capital Asset Pricing Model (CAPM).
+    /// Formula: risk_free_rate + beta * market_risk_premium
+    // Calcula o custo do capital próprio usando o CAPM (Capital Asset Pricing Model).
+    // Fórmula: taxa_livre_de_risco + beta * prêmio_de_risco_de_mercado
+    pub fn cost_of_equity(risk_free_rate: f64, beta: f64, market_risk_premium: f64) -> f64 {
+        risk_free_rate + beta * market_risk_premium
+    }
+
+    /// Calculates the Weighted Average Cost of Capital (WACC), applying the tax shield to the
+    /// cost of debt.
+    /// Formula: (equity_value / (equity_value + debt_value)) * cost_of_equity + (debt_value / (equity_value + debt_value)) * cost_of_debt * (1 - tax_rate)
+    // Calcula o Custo Médio Ponderado de Capital (WACC), aplicando o benefício fiscal ao custo da dívida.
+    // Fórmula: (valor_capital_próprio / (valor_capital_próprio + valor_dívida)) * custo_capital_próprio + (valor_dívida / (valor_capital_próprio + valor_dívida)) * custo_dívida * (1 - alíquota)
+    pub fn wacc(
+        cost_of_equity: f64,
+        cost_of_debt: f64,
+        tax_rate: f64,
+        equity_value: f64,
+        debt_value: f64,
+    ) -> Option<f64> {
+        let total_value = equity_value + debt_value;
+        if total_value == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            let equity_weight = equity_value / total_value;
+            let debt_weight = debt_value / total_value;
+            Some(equity_weight * cost_of_equity + debt_weight * cost_of_debt * (1.0 - tax_rate))
+        }
+    }
+
+    /// Calculates intrinsic equity value using the excess-return (residual income) model, which
+    /// values a company from its book value plus the present value of future excess returns
+    /// rather than from free cash flow.
+    /// Formula: book_value + Σ (excess_return_t / (1 + cost_of_equity)^t) + discounted terminal value,
+    /// where excess_return_t = (ROE - cost_of_equity) * book_value_begin_of_year and book value grows
+    /// by retained earnings: book_value * ROE * (1 - payout_ratio).
+    /// Returns `None` when `cost_of_equity <= perpetuity_growth_rate`, since the terminal value formula diverges.
+    // Calcula o valor intrínseco do capital próprio usando o modelo de lucro residual (excess return),
+    // que avalia uma empresa a partir do valor contábil somado ao valor presente dos retornos excedentes
+    // futuros, em vez do fluxo de caixa livre.
+    // Fórmula: valor_contábil + Σ (retorno_excedente_t / (1 + custo_capital_próprio)^t) + valor terminal descontado,
+    // onde retorno_excedente_t = (ROE - custo_capital_próprio) * valor_contábil_início_do_ano e o valor contábil
+    // cresce pelos lucros retidos: valor_contábil * ROE * (1 - taxa_payout).
+    // Retorna `None` quando `cost_of_equity <= perpetuity_growth_rate`, pois a fórmula do valor terminal diverge.
+    pub fn excess_return_value(
+        book_value_of_equity: f64,
+        roe: f64,
+        cost_of_equity: f64,
+        payout_ratio: f64,
+        years: u32,
+        perpetuity_growth_rate: f64,
+    ) -> Option<f64> {
+        if cost_of_equity <= perpetuity_growth_rate {
+            None // Avoid a diverging/negative terminal value
+            // Evita um valor terminal divergente/negativo
+        } else {
+            let mut total_value = book_value_of_equity;
+            let mut book_value_begin_of_year = book_value_of_equity;
+            for t in 1..=years {
+                let excess_return = (roe - cost_of_equity) * book_value_begin_of_year;
+                total_value += excess_return / (1.0 + cost_of_equity).powf(t as f64);
+                // Book value grows by retained earnings for the next year
+                // Valor contábil cresce pelos lucros retidos no ano seguinte
+                book_value_begin_of_year += book_value_begin_of_year * roe * (1.0 - payout_ratio);
+            }
+            // Terminal excess-return value on the first post-projection year, discounted back to present
+            // Valor terminal do retorno excedente no primeiro ano pós-projeção, descontado a valor presente
+            let terminal_excess_return = (roe - cost_of_equity) * book_value_begin_of_year;
+            let terminal_value = terminal_excess_return / (cost_of_equity - perpetuity_growth_rate);
+            let discounted_terminal_value = terminal_value / (1.0 + cost_of_equity).powf(years as f64);
+            total_value += discounted_terminal_value;
+            Some(total_value)
+        }
+    }
+
+    /// Calculates excess-return intrinsic value per share.
+    // Calcula o valor intrínseco por ação pelo modelo de retorno excedente.
+    pub fn excess_return_value_per_share(
+        book_value_of_equity: f64,
+        roe: f64,
+        cost_of_equity: f64,
+        payout_ratio: f64,
+        years: u32,
+        perpetuity_growth_rate: f64,
+        shares_outstanding: f64,
+    ) -> Option<f64> {
+        if shares_outstanding == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            excess_return_value(book_value_of_equity, roe, cost_of_equity, payout_ratio, years, perpetuity_growth_rate)
+                .map(|value| value / shares_outstanding)
+        }
+    }
+
+    /// Calculates the Net Present Value (NPV) of an arbitrary cash-flow stream.
+    /// `cash_flows[0]` is treated as time 0 and is not discounted; `cash_flows[t]` is discounted
+    /// by `(1 + discount_rate)^t`.
+    /// Formula: Σ cash_flows[t] / (1 + discount_rate)^t for t=0 to cash_flows.len()-1
+    // Calcula o Valor Presente Líquido (VPL) de um fluxo de caixa arbitrário.
+    // `cash_flows[0]` é tratado como tempo 0 e não é descontado; `cash_flows[t]` é descontado
+    // por `(1 + discount_rate)^t`.
+    // Fórmula: Σ cash_flows[t] / (1 + discount_rate)^t para t=0 até cash_flows.len()-1
+    pub fn net_present_value(discount_rate: f64, cash_flows: &[f64]) -> f64 {
+        cash_flows
+            .iter()
+            .enumerate()
+            .map(|(t, cash_flow)| cash_flow / (1.0 + discount_rate).powf(t as f64))
+            .sum()
+    }
+
+    /// Solves for the Internal Rate of Return (IRR), the discount rate at which the NPV of the
+    /// cash-flow stream is zero, using Newton–Raphson iteration starting from `guess` (default 0.1).
+    /// Returns `None` if the derivative is ~0 at some step or the iteration fails to converge
+    /// within 100 steps.
+    // Resolve a Taxa Interna de Retorno (TIR), a taxa de desconto na qual o VPL do fluxo de caixa
+    // é zero, usando iteração de Newton–Raphson a partir de `guess` (padrão 0.1).
+    // Retorna `None` se a derivada for ~0 em algum passo ou a iteração não convergir em 100 passos.
+    pub fn internal_rate_of_return(cash_flows: &[f64], guess: Option<f64>) -> Option<f64> {
+        if cash_flows.len() < 2 || cash_flows.iter().all(|cash_flow| *cash_flow == 0.0) {
+            return None; // No well-defined IRR for too few or all-zero cash flows
+            // Não há TIR bem definida para poucos fluxos de caixa ou fluxos todos nulos
+        }
+        let mut rate = guess.unwrap_or(0.1);
+        for _ in 0..100 {
+            let npv = net_present_value(rate, cash_flows);
+            if npv.abs() < 1e-7 {
+                return Some(rate);
+            }
+            // NPV'(r) = Σ -t * CF_t / (1 + r)^(t + 1)
+            let npv_derivative: f64 = cash_flows
+                .iter()
+                .enumerate()
+                .map(|(t, cash_flow)| -(t as f64) * cash_flow / (1.0 + rate).powf(t as f64 + 1.0))
+                .sum();
+            if npv_derivative.abs() < 1e-10 {
+                return None; // Avoid division by a near-zero derivative
+                // Evita divisão por uma derivada próxima de zero
+            }
+            rate -= npv / npv_derivative;
+        }
+        None // Did not converge within 100 iterations
+        // Não convergiu em 100 iterações
+    }
+
+    /// Calculates the margin of safety between an intrinsic value per share and the market price.
+    /// Formula: (intrinsic_value_per_share - market_price) / intrinsic_value_per_share * 100
+    // Calcula a margem de segurança entre o valor intrínseco por ação e o preço de mercado.
+    // Fórmula: (valor_intrínseco_por_ação - preço_de_mercado) / valor_intrínseco_por_ação * 100
+    pub fn margin_of_safety(intrinsic_value_per_share: f64, market_price: f64) -> Option<f64> {
+        if intrinsic_value_per_share == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            Some((intrinsic_value_per_share - market_price) / intrinsic_value_per_share * 100.0)
+        }
+    }
+
+    /// Decides whether a stock is a buy: true only when its margin of safety meets or exceeds
+    /// `required_margin_pct`.
+    // Decide se uma ação é uma compra: verdadeiro apenas quando a margem de segurança atinge ou
+    // supera `required_margin_pct`.
+    pub fn is_buy(intrinsic_value_per_share: f64, market_price: f64, required_margin_pct: f64) -> bool {
+        match margin_of_safety(intrinsic_value_per_share, market_price) {
+            Some(margin) => margin >= required_margin_pct,
+            None => false,
+        }
+    }
+
+    /// Calculates the after-tax gain on a sale, taxing only positive gains.
+    /// Formula: sale_price > purchase_price ? (sale_price - purchase_price) * (1 - capital_gains_rate) : sale_price - purchase_price
+    // Calcula o ganho após impostos em uma venda, tributando apenas ganhos positivos.
+    // Fórmula: preço_de_venda > preço_de_compra ? (preço_de_venda - preço_de_compra) * (1 - alíquota_ganho_de_capital) : preço_de_venda - preço_de_compra
+    pub fn after_tax_gain(purchase_price: f64, sale_price: f64, capital_gains_rate: f64) -> f64 {
+        let gain = sale_price - purchase_price;
+        if gain > 0.0 {
+            gain * (1.0 - capital_gains_rate)
+        } else {
+            gain // Losses are returned untaxed
+            // Perdas são retornadas sem tributação
+        }
+    }
+}
+
+/// Liquidity and leverage ratios that assess balance-sheet solvency.
+// Índices de liquidez e alavancagem que avaliam a solidez do balanço patrimonial.
+pub mod solvency {
+    /// Calculates the Current Ratio.
+    /// Formula: Current Assets / Current Liabilities
+    // Calcula o Índice de Liquidez Corrente.
+    // Fórmula: Ativos Circulantes / Passivos Circulantes
+    pub fn current_ratio(current_assets: f64, current_liabilities: f64) -> Option<f64> {
+        if current_liabilities == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            Some(current_assets / current_liabilities)
+        }
+    }
+
+    /// Calculates the Quick Ratio (acid-test).
+    /// Formula: (Current Assets - Inventories) / Current Liabilities
+    // Calcula o Índice de Liquidez Seca.
+    // Fórmula: (Ativos Circulantes - Estoques) / Passivos Circulantes
+    pub fn quick_ratio(current_assets: f64, inventories: f64, current_liabilities: f64) -> Option<f64> {
+        if current_liabilities == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            Some((current_assets - inventories) / current_liabilities)
+        }
+    }
+
+    /// Calculates the Cash Ratio.
+    /// Formula: Cash / Current Liabilities
+    // Calcula o Índice de Liquidez Imediata.
+    // Fórmula: Caixa / Passivos Circulantes
+    pub fn cash_ratio(cash: f64, current_liabilities: f64) -> Option<f64> {
+        if current_liabilities == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            Some(cash / current_liabilities)
+        }
+    }
+
+    /// Calculates the Debt Ratio.
+    /// Formula: Total Liabilities / Total Assets
+    // Calcula o Índice de Endividamento.
+    // Fórmula: Passivos Totais / Ativos Totais
+    pub fn debt_ratio(total_liabilities: f64, total_assets: f64) -> Option<f64> {
+        if total_assets == 0.0 {
+            None // Avoid division by zero
+            // Evita divisão por zero
+        } else {
+            Some(total_liabilities / total_assets)
+        }
+    }
 }
+
+/// A time-series view over a company's financials, so multi-year screens (e.g. "ROE above 15%
+/// for 10 consecutive years") can be computed without the caller re-threading the same numbers
+/// for every fiscal year.
+// Uma visão de série temporal das finanças de uma empresa, para que análises de múltiplos anos
+// (ex.: "ROE acima de 15% por 10 anos consecutivos") possam ser calculadas sem que quem chama
+// precise re-passar os mesmos números para cada ano fiscal.
+pub mod financials {
+    use std::collections::BTreeMap;
+    use super::wb_valuation::{owners_earnings, return_on_equity, eps_cagr};
+
+    /// The per-year line items needed to compute the existing valuation and ratio functions.
+    // Os itens de linha de cada ano necessários para calcular as funções de valuation e índices existentes.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct FinancialYear {
+        pub net_income: f64,
+        pub shareholders_equity: f64,
+        pub total_assets: f64,
+        pub total_liabilities: f64,
+        pub intangible_assets: f64,
+        pub depreciation_amortization: f64,
+        pub maintenance_capex: f64,
+        pub shares_outstanding: f64,
+    }
+
+    /// A company's financial history, keyed by fiscal year.
+    // O histórico financeiro de uma empresa, indexado por ano fiscal.
+    #[derive(Debug, Clone, Default)]
+    pub struct Financials {
+        years: BTreeMap<u32, FinancialYear>,
+    }
+
+    impl Financials {
+        /// Creates an empty financial history.
+        // Cria um histórico financeiro vazio.
+        pub fn new() -> Self {
+            Financials { years: BTreeMap::new() }
+        }
+
+        /// Records (or replaces) the line items for a fiscal year.
+        // Registra (ou substitui) os itens de linha de um ano fiscal.
+        pub fn insert_year(&mut self, fiscal_year: u32, data: FinancialYear) {
+            self.years.insert(fiscal_year, data);
+        }
+
+        /// Calculates Return on Equity for every recorded year.
+        // Calcula o Retorno sobre o Patrimônio para cada ano registrado.
+        pub fn roe_series(&self) -> BTreeMap<u32, Option<f64>> {
+            self.years
+                .iter()
+                .map(|(&fiscal_year, data)| (fiscal_year, return_on_equity(data.net_income, data.shareholders_equity)))
+                .collect()
+        }
+
+        /// Calculates Owner's Earnings for every recorded year.
+        // Calcula os Lucros do Proprietário para cada ano registrado.
+        pub fn owners_earnings_series(&self) -> BTreeMap<u32, f64> {
+            self.years
+                .iter()
+                .map(|(&fiscal_year, data)| {
+                    (
+                        fiscal_year,
+                        owners_earnings(data.net_income, data.depreciation_amortization, data.maintenance_capex),
+                    )
+                })
+                .collect()
+        }
+
+        /// Calculates EPS CAGR over the most recent `n_years` of recorded history, feeding
+        /// `eps_cagr` from the first and last available EPS in that window.
+        /// Returns `None` when fewer than `n_years` (or fewer than 2) years are recorded, when
+        /// either end of the window has zero shares outstanding, or when the underlying
+        /// `eps_cagr` guard fails (e.g. zero initial EPS).
+        // Calcula o CAGR do EPS ao longo dos `n_years` mais recentes do histórico registrado,
+        // alimentando `eps_cagr` a partir do primeiro e do último EPS disponíveis nessa janela.
+        // Retorna `None` quando há menos de `n_years` (ou menos de 2) anos registrados, quando
+        // alguma ponta da janela tem zero ações em circulação, ou quando a validação de
+        // `eps_cagr` falha (ex.: EPS inicial zero).
+        pub fn eps_cagr_over(&self, n_years: u32) -> Option<f64> {
+            if n_years < 2 || (self.years.len() as u32) < n_years {
+                return None;
+            }
+            let window: Vec<(&u32, &FinancialYear)> = self.years.iter().rev().take(n_years as usize).collect();
+            let &(last_year, last_data) = window.first()?;
+            let &(first_year, first_data) = window.last()?;
+            if first_data.shares_outstanding == 0.0 || last_data.shares_outstanding == 0.0 {
+                return None;
+            }
+            let initial_eps = first_data.net_income / first_data.shares_outstanding;
+            let final_eps = last_data.net_income / last_data.shares_outstanding;
+            eps_cagr(initial_eps, final_eps, (last_year - first_year) as f64)
+        }
+    }
+}
+
 /// Unit tests for the valuation functions.
 // Testes unitários para as funções de valuation.
 #[cfg(test)]
 mod tests {
     use super::wb_valuation::*;
+    use super::solvency::*;
+    use super::financials::*;
 
 
     #[test]
@@ -195,4 +574,194 @@ mod tests {
         assert!(intrinsic_value_per_share(1000.0, 0.05, 0.1, 10, 0.0).is_none());
         // Verifica que retorna None para ações em circulação zero
     }
+
+    #[test]
+    fn test_intrinsic_value_with_terminal() {
+        let result = intrinsic_value_with_terminal(1000.0, 0.05, 0.1, 10, 0.03).unwrap();
+        // Explicit-period value plus a discounted Gordon-growth terminal value
+        // Valor do período explícito mais um valor terminal de crescimento de Gordon descontado
+        assert!(result > intrinsic_value(1000.0, 0.05, 0.1, 10));
+        assert!(intrinsic_value_with_terminal(1000.0, 0.05, 0.1, 10, 0.1).is_none());
+        // Verifica que retorna None quando discount_rate <= perpetuity_growth_rate
+        assert!(intrinsic_value_with_terminal(1000.0, 0.05, 0.1, 10, 0.15).is_none());
+    }
+
+    #[test]
+    fn test_cost_of_equity() {
+        let result = cost_of_equity(0.04, 1.2, 0.05);
+        assert_eq!(result, 0.1); // 0.04 + 1.2 * 0.05
+    }
+
+    #[test]
+    fn test_wacc() {
+        let ke = cost_of_equity(0.04, 1.2, 0.05);
+        let result = wacc(ke, 0.06, 0.25, 7000.0, 3000.0).unwrap();
+        assert!((result - 0.0835).abs() < 0.0001); // 0.7 * 0.1 + 0.3 * 0.06 * 0.75
+        assert!(wacc(ke, 0.06, 0.25, 0.0, 0.0).is_none());
+        // Verifica que retorna None para capital total zero
+    }
+
+    #[test]
+    fn test_excess_return_value() {
+        let result = excess_return_value(1000.0, 0.15, 0.1, 0.5, 10, 0.03).unwrap();
+        // ROE above cost of equity should produce a value above book value
+        // ROE acima do custo do capital próprio deve gerar valor acima do valor contábil
+        assert!(result > 1000.0);
+        assert!(excess_return_value(1000.0, 0.15, 0.1, 0.5, 10, 0.1).is_none());
+        // Verifica que retorna None quando cost_of_equity <= perpetuity_growth_rate
+    }
+
+    #[test]
+    fn test_excess_return_value_per_share() {
+        let result = excess_return_value_per_share(1000.0, 0.15, 0.1, 0.5, 10, 0.03, 100.0).unwrap();
+        assert_eq!(
+            result,
+            excess_return_value(1000.0, 0.15, 0.1, 0.5, 10, 0.03).unwrap() / 100.0
+        );
+        assert!(excess_return_value_per_share(1000.0, 0.15, 0.1, 0.5, 10, 0.03, 0.0).is_none());
+        // Verifica que retorna None para ações em circulação zero
+    }
+
+    #[test]
+    fn test_net_present_value() {
+        let cash_flows = [-1000.0, 300.0, 400.0, 500.0, 600.0];
+        let result = net_present_value(0.1, &cash_flows);
+        assert!((result - 388.77).abs() < 0.1); // Hand-calculated for verification
+        // Calculado manualmente para verificação
+    }
+
+    #[test]
+    fn test_internal_rate_of_return() {
+        let cash_flows = [-1000.0, 300.0, 400.0, 500.0, 600.0];
+        let result = internal_rate_of_return(&cash_flows, None).unwrap();
+        // The IRR should zero out the NPV computed at that rate
+        // A TIR deve zerar o VPL calculado naquela taxa
+        assert!(net_present_value(result, &cash_flows).abs() < 1e-6);
+        assert!((result - 0.2489).abs() < 0.001); // Hand-calculated for verification
+        // Calculado manualmente para verificação
+    }
+
+    #[test]
+    fn test_internal_rate_of_return_degenerate_inputs() {
+        assert!(internal_rate_of_return(&[], None).is_none());
+        // Verifica que retorna None para fluxo de caixa vazio
+        assert!(internal_rate_of_return(&[-1000.0], None).is_none());
+        // Verifica que retorna None para um único fluxo de caixa
+        assert!(internal_rate_of_return(&[0.0, 0.0, 0.0], None).is_none());
+        // Verifica que retorna None para fluxos de caixa todos nulos
+    }
+
+    #[test]
+    fn test_current_ratio() {
+        let result = current_ratio(2000.0, 1000.0).unwrap();
+        assert_eq!(result, 2.0); // 2000 / 1000
+        assert!(current_ratio(2000.0, 0.0).is_none());
+        // Verifica que retorna None para passivos circulantes zero
+    }
+
+    #[test]
+    fn test_quick_ratio() {
+        let result = quick_ratio(2000.0, 500.0, 1000.0).unwrap();
+        assert_eq!(result, 1.5); // (2000 - 500) / 1000
+        assert!(quick_ratio(2000.0, 500.0, 0.0).is_none());
+        // Verifica que retorna None para passivos circulantes zero
+    }
+
+    #[test]
+    fn test_cash_ratio() {
+        let result = cash_ratio(800.0, 1000.0).unwrap();
+        assert_eq!(result, 0.8); // 800 / 1000
+        assert!(cash_ratio(800.0, 0.0).is_none());
+        // Verifica que retorna None para passivos circulantes zero
+    }
+
+    #[test]
+    fn test_debt_ratio() {
+        let result = debt_ratio(4000.0, 10000.0).unwrap();
+        assert_eq!(result, 0.4); // 4000 / 10000
+        assert!(debt_ratio(4000.0, 0.0).is_none());
+        // Verifica que retorna None para ativos totais zero
+    }
+
+    fn sample_financials() -> Financials {
+        let mut financials = Financials::new();
+        financials.insert_year(2019, FinancialYear {
+            net_income: 500.0,
+            shareholders_equity: 2000.0,
+            total_assets: 5000.0,
+            total_liabilities: 3000.0,
+            intangible_assets: 0.0,
+            depreciation_amortization: 100.0,
+            maintenance_capex: 80.0,
+            shares_outstanding: 100.0,
+        });
+        financials.insert_year(2023, FinancialYear {
+            net_income: 900.0,
+            shareholders_equity: 3000.0,
+            total_assets: 7000.0,
+            total_liabilities: 3500.0,
+            intangible_assets: 0.0,
+            depreciation_amortization: 150.0,
+            maintenance_capex: 120.0,
+            shares_outstanding: 100.0,
+        });
+        financials
+    }
+
+    #[test]
+    fn test_roe_series() {
+        let financials = sample_financials();
+        let series = financials.roe_series();
+        assert_eq!(series[&2019].unwrap(), 25.0); // (500 / 2000) * 100
+        assert_eq!(series[&2023].unwrap(), 30.0); // (900 / 3000) * 100
+    }
+
+    #[test]
+    fn test_owners_earnings_series() {
+        let financials = sample_financials();
+        let series = financials.owners_earnings_series();
+        assert_eq!(series[&2019], 520.0); // 500 + 100 - 80
+        assert_eq!(series[&2023], 930.0); // 900 + 150 - 120
+    }
+
+    #[test]
+    fn test_eps_cagr_over() {
+        let financials = sample_financials();
+        let result = financials.eps_cagr_over(2).unwrap();
+        // EPS grows from 5.0 in 2019 to 9.0 in 2023, over 4 years
+        assert!((result - eps_cagr(5.0, 9.0, 4.0).unwrap()).abs() < 1e-9);
+        assert!(financials.eps_cagr_over(3).is_none());
+        // Verifica que retorna None quando há menos anos registrados que n_years
+    }
+
+    #[test]
+    fn test_margin_of_safety() {
+        let result = margin_of_safety(100.0, 70.0).unwrap();
+        assert_eq!(result, 30.0); // (100 - 70) / 100 * 100
+        assert!(margin_of_safety(0.0, 70.0).is_none());
+        // Verifica que retorna None para valor intrínseco zero
+    }
+
+    #[test]
+    fn test_is_buy() {
+        assert!(is_buy(100.0, 70.0, 25.0));
+        assert!(!is_buy(100.0, 90.0, 25.0));
+        assert!(!is_buy(0.0, 70.0, 25.0));
+        // Verifica que retorna false quando margin_of_safety não pode ser calculada
+    }
+
+    #[test]
+    fn test_after_tax_owners_earnings() {
+        let result = after_tax_owners_earnings(1000.0, 200.0, 150.0, 0.25);
+        assert_eq!(result, 787.5); // 1050 * (1 - 0.25)
+    }
+
+    #[test]
+    fn test_after_tax_gain() {
+        let result = after_tax_gain(100.0, 150.0, 0.2);
+        assert_eq!(result, 40.0); // (150 - 100) * (1 - 0.2)
+        let loss = after_tax_gain(150.0, 100.0, 0.2);
+        assert_eq!(loss, -50.0); // Losses are untaxed
+        // Perdas não são tributadas
+    }
 }
\ No newline at end of file